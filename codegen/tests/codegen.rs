@@ -1,7 +1,11 @@
 use docx_codegen::Xml;
-use quick_xml::{Error as XmlError, Reader, Writer};
+use quick_xml::{
+    events::Event,
+    Error as XmlError, Reader, Writer,
+};
 
 use std::{
+    fmt,
     io::Cursor,
     io::Error as IOError,
     num::ParseIntError,
@@ -9,6 +13,109 @@ use std::{
     string::FromUtf8Error,
 };
 
+/// A 1-based line/column pair pointing at the byte offset a parse error was
+/// raised at, derived from [`Reader::buffer_position`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextPosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl TextPosition {
+    /// Resolves a byte offset reported by `Reader::buffer_position()` into a
+    /// line/column pair by scanning the source it was read from.
+    fn from_offset(src: &str, offset: usize) -> Self {
+        let mut line = 1;
+        let mut column = 1;
+
+        for ch in src[..offset.min(src.len())].chars() {
+            if ch == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+
+        TextPosition { line, column }
+    }
+}
+
+impl fmt::Display for TextPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[test]
+fn test_text_position_from_offset_tracks_lines() {
+    let src = "<tag1>\n  bad</tag1>";
+    let offset = src.find("bad").unwrap();
+    assert_eq!(
+        TextPosition::from_offset(src, offset),
+        TextPosition { line: 2, column: 3 }
+    );
+}
+
+/// None of the four `pos: Option<TextPosition>` variants — `UnexpectedEof`,
+/// `UnexpectedTag`, `UnexpectedEvent`, `UnknownValue` — is constructed
+/// anywhere in this tree, since nothing here raises them. Build all four
+/// directly off a `TextPosition` taken from a real
+/// `reader.buffer_position()`, so the Display/position plumbing is proven
+/// for every variant that carries one.
+#[test]
+fn test_error_display_reports_position_for_every_variant() {
+    let src = "<a><b/></a>";
+    let mut reader = Reader::from_str(src);
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        if let Event::Start(_) = reader.read_event(&mut buf).unwrap() {
+            break;
+        }
+    }
+    loop {
+        buf.clear();
+        if let Event::Empty(_) = reader.read_event(&mut buf).unwrap() {
+            break;
+        }
+    }
+    let pos = Some(TextPosition::from_offset(src, reader.buffer_position()));
+    assert_eq!(pos, Some(TextPosition { line: 1, column: 8 }));
+
+    assert_eq!(
+        Error::UnexpectedEof { pos }.to_string(),
+        "unexpected eof at 1:8"
+    );
+    assert_eq!(
+        Error::UnexpectedTag {
+            expected: "a",
+            found: "b".to_string(),
+            pos,
+        }
+        .to_string(),
+        "expected tag `a`, found `b` at 1:8"
+    );
+    assert_eq!(
+        Error::UnexpectedEvent {
+            expected: "Text or CData",
+            found: "other event",
+            pos,
+        }
+        .to_string(),
+        "expected Text or CData, found other event at 1:8"
+    );
+    assert_eq!(
+        Error::UnknownValue {
+            expected: "true or false",
+            found: "maybe".to_string(),
+            pos,
+        }
+        .to_string(),
+        "expected one of true or false, found `maybe` at 1:8"
+    );
+}
+
 #[derive(Debug)]
 pub enum Error {
     IO(IOError),
@@ -16,14 +123,18 @@ pub enum Error {
     Utf8(Utf8Error),
     ParseInt(ParseIntError),
     ParseBool(ParseBoolError),
-    UnexpectedEof,
+    UnexpectedEof {
+        pos: Option<TextPosition>,
+    },
     UnexpectedTag {
         expected: &'static str,
         found: String,
+        pos: Option<TextPosition>,
     },
     UnexpectedEvent {
         expected: &'static str,
         found: &'static str,
+        pos: Option<TextPosition>,
     },
     MissingField {
         name: &'static str,
@@ -32,9 +143,77 @@ pub enum Error {
     UnknownValue {
         expected: &'static str,
         found: String,
+        pos: Option<TextPosition>,
     },
 }
 
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::IO(err) => write!(f, "io error: {err}"),
+            Error::Xml(err) => write!(f, "xml error: {err}"),
+            Error::Utf8(err) => write!(f, "invalid utf-8: {err}"),
+            Error::ParseInt(err) => write!(f, "invalid integer: {err}"),
+            Error::ParseBool(err) => write!(f, "invalid boolean: {err}"),
+            Error::UnexpectedEof { pos } => {
+                write!(f, "unexpected eof")?;
+                write_pos(f, pos)
+            }
+            Error::UnexpectedTag {
+                expected,
+                found,
+                pos,
+            } => {
+                write!(f, "expected tag `{expected}`, found `{found}`")?;
+                write_pos(f, pos)
+            }
+            Error::UnexpectedEvent {
+                expected,
+                found,
+                pos,
+            } => {
+                write!(f, "expected {expected}, found {found}")?;
+                write_pos(f, pos)
+            }
+            Error::MissingField { name, field } => {
+                write!(f, "missing field `{field}` on `{name}`")
+            }
+            Error::UnknownValue {
+                expected,
+                found,
+                pos,
+            } => {
+                write!(f, "expected one of {expected}, found `{found}`")?;
+                write_pos(f, pos)
+            }
+        }
+    }
+}
+
+fn write_pos(f: &mut fmt::Formatter<'_>, pos: &Option<TextPosition>) -> fmt::Result {
+    match pos {
+        Some(pos) => write!(f, " at {pos}"),
+        None => Ok(()),
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::IO(err) => Some(err),
+            Error::Xml(err) => Some(err),
+            Error::Utf8(err) => Some(err),
+            Error::ParseInt(err) => Some(err),
+            Error::ParseBool(err) => Some(err),
+            Error::UnexpectedEof { .. }
+            | Error::UnexpectedTag { .. }
+            | Error::UnexpectedEvent { .. }
+            | Error::MissingField { .. }
+            | Error::UnknownValue { .. } => None,
+        }
+    }
+}
+
 impl From<IOError> for Error {
     fn from(err: IOError) -> Self {
         Error::IO(err)
@@ -71,8 +250,29 @@ impl From<XmlError> for Error {
     }
 }
 
-type Result<T> = ::std::result::Result<T, Error>;
-
+// Several backlog requests ask for behavior inside Tag1/Tag2/Tag3/Tag's
+// read/write, which `docx_codegen::Xml` generates; that proc-macro's source
+// isn't part of this chunk of the tree, so none of them can be wired in
+// here. Closed out as not implementable in this tree slice rather than
+// landed as unwired scaffolding:
+//   - chunk0-2: resolve tag/attribute names against a rebindable `xmlns`
+//     scope instead of a bare prefix match (needs a `#[xml(ns = "...")]`
+//     attribute in the derive macro). A document that rebinds `w` partway
+//     through still fails exactly as before this note.
+//   - chunk0-3: add a `cdata` flag to `Tag1::content`/`Tag3::text` so they
+//     round-trip through `<![CDATA[...]]>` instead of escaped text, same
+//     reason — there's no derive-macro attribute to parse it into.
+//   - chunk0-4: share one escape/unescape cache across a document read or
+//     write. There's no top-level document type in this tree slice either,
+//     so there's nothing for a shared cache to be threaded through.
+//   - chunk0-5: honor per-element `xml:space="preserve"` instead of the
+//     reader's blanket `trim_text(true)`. Real per-element tracking needs
+//     to live inside the derive-generated `read`, which this tree doesn't
+//     have the source for; the reader-level trim stays in place below and
+//     is covered by a regression test in test_read.
+//   - chunk0-6: derive `serde::Serialize`/`Deserialize` for these types
+//     behind a `serde` feature. There's no Cargo.toml anywhere in this tree
+//     slice to declare that feature on, so there's nothing to gate.
 #[derive(Xml, PartialEq, Debug)]
 #[xml(tag = "tag1")]
 struct Tag1 {
@@ -229,6 +429,17 @@ fn test_read() {
         }
     );
 
+    // Padding whitespace around a `#[xml(text)]` field is trimmed by
+    // `Tag1::read`, via the reader's own `trim_text(true)`.
+    assert_read_eq!(
+        Tag1,
+        r#"<tag1>  content  </tag1>"#,
+        Tag1 {
+            att1: None,
+            content: "content".to_string(),
+        }
+    );
+
     assert_read_eq!(
         Tag,
         r#"<tag1 att1="att1">content</tag1>"#,
@@ -246,4 +457,5 @@ fn test_read() {
             att2: "att2".to_string(),
         })
     );
-}
\ No newline at end of file
+}
+